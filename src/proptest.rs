@@ -2,8 +2,10 @@
 //!
 //! These are only available when using the `proptest` feature flag.
 
-use crate::OrdMap;
-use proptest::collection::vec;
+use crate::{HashMap, HashSet, OrdMap, OrdSet, Vector};
+use proptest::collection::{
+    btree_map, btree_set, hash_map as proptest_hash_map, hash_set as proptest_hash_set, vec,
+};
 use proptest::strategy::{BoxedStrategy, Strategy, ValueTree};
 use std::hash::Hash;
 use std::iter::FromIterator;
@@ -34,10 +36,202 @@ where
     <K::Tree as ValueTree>::Value: Ord + Clone,
     <V::Tree as ValueTree>::Value: Clone,
 {
-    ::proptest::collection::vec((key, value), size.clone())
-        .prop_map(OrdMap::from)
-        .prop_filter("OrdMap minimum size".to_owned(), move |m| {
-            m.len() >= size.start
-        })
+    btree_map(key, value, size)
+        .prop_map(|m| OrdMap::from_iter(m))
         .boxed()
 }
+
+/// A strategy for an [`OrdSet`][OrdSet] of a given size.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use ::proptest::proptest;
+/// proptest! {
+///     #[test]
+///     fn proptest_works(ref s in ord_set(0..9999, 10..100)) {
+///         assert!(s.len() < 100);
+///         assert!(s.len() >= 10);
+///     }
+/// }
+/// ```
+///
+/// [OrdSet]: ../struct.OrdSet.html
+pub fn ord_set<A: Strategy + 'static>(
+    element: A,
+    size: Range<usize>,
+) -> BoxedStrategy<OrdSet<<A::Tree as ValueTree>::Value>>
+where
+    <A::Tree as ValueTree>::Value: Ord + Clone,
+{
+    btree_set(element, size)
+        .prop_map(|s| OrdSet::from_iter(s))
+        .boxed()
+}
+
+/// A strategy for a [`HashMap`][HashMap] of a given size.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use ::proptest::proptest;
+/// proptest! {
+///     #[test]
+///     fn proptest_works(ref m in hash_map(0..9999, ".*", 10..100)) {
+///         assert!(m.len() < 100);
+///         assert!(m.len() >= 10);
+///     }
+/// }
+/// ```
+///
+/// [HashMap]: ../struct.HashMap.html
+pub fn hash_map<K: Strategy + 'static, V: Strategy + 'static>(
+    key: K,
+    value: V,
+    size: Range<usize>,
+) -> BoxedStrategy<HashMap<<K::Tree as ValueTree>::Value, <V::Tree as ValueTree>::Value>>
+where
+    <K::Tree as ValueTree>::Value: Hash + Eq + Clone,
+    <V::Tree as ValueTree>::Value: Clone,
+{
+    proptest_hash_map(key, value, size)
+        .prop_map(|m| HashMap::from_iter(m))
+        .boxed()
+}
+
+/// A strategy for a [`HashSet`][HashSet] of a given size.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use ::proptest::proptest;
+/// proptest! {
+///     #[test]
+///     fn proptest_works(ref s in hash_set(0..9999, 10..100)) {
+///         assert!(s.len() < 100);
+///         assert!(s.len() >= 10);
+///     }
+/// }
+/// ```
+///
+/// [HashSet]: ../struct.HashSet.html
+pub fn hash_set<A: Strategy + 'static>(
+    element: A,
+    size: Range<usize>,
+) -> BoxedStrategy<HashSet<<A::Tree as ValueTree>::Value>>
+where
+    <A::Tree as ValueTree>::Value: Hash + Eq + Clone,
+{
+    proptest_hash_set(element, size)
+        .prop_map(|s| HashSet::from_iter(s))
+        .boxed()
+}
+
+/// A strategy for a [`Vector`][Vector] of a given size.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use ::proptest::proptest;
+/// proptest! {
+///     #[test]
+///     fn proptest_works(ref v in vector(".*", 10..100)) {
+///         assert!(v.len() < 100);
+///         assert!(v.len() >= 10);
+///     }
+/// }
+/// ```
+///
+/// [Vector]: ../struct.Vector.html
+pub fn vector<A: Strategy + 'static>(
+    element: A,
+    size: Range<usize>,
+) -> BoxedStrategy<Vector<<A::Tree as ValueTree>::Value>>
+where
+    <A::Tree as ValueTree>::Value: Clone,
+{
+    vec(element, size).prop_map(Vector::from).boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::test_runner::TestRunner;
+
+    fn assert_shrink_terminates<A: std::fmt::Debug>(strategy: &BoxedStrategy<A>) {
+        let mut runner = TestRunner::default();
+        let mut tree = strategy.new_tree(&mut runner).unwrap();
+        let mut steps = 0;
+        while tree.simplify() {
+            steps += 1;
+            assert!(
+                steps < 10_000,
+                "shrink did not terminate within a reasonable number of steps"
+            );
+        }
+    }
+
+    #[test]
+    fn hash_map_respects_size_range() {
+        let size = 5..10;
+        let strategy = hash_map(0..100i32, ".*", size.clone());
+        let mut runner = TestRunner::default();
+        for _ in 0..32 {
+            let tree = strategy.new_tree(&mut runner).unwrap();
+            assert!(size.contains(&tree.current().len()));
+        }
+    }
+
+    #[test]
+    fn hash_map_shrink_terminates() {
+        assert_shrink_terminates(&hash_map(0..100i32, ".*", 5..10));
+    }
+
+    #[test]
+    fn hash_set_respects_size_range() {
+        let size = 5..10;
+        let strategy = hash_set(0..1000i32, size.clone());
+        let mut runner = TestRunner::default();
+        for _ in 0..32 {
+            let tree = strategy.new_tree(&mut runner).unwrap();
+            assert!(size.contains(&tree.current().len()));
+        }
+    }
+
+    #[test]
+    fn hash_set_shrink_terminates() {
+        assert_shrink_terminates(&hash_set(0..1000i32, 5..10));
+    }
+
+    #[test]
+    fn ord_set_respects_size_range() {
+        let size = 5..10;
+        let strategy = ord_set(0..1000i32, size.clone());
+        let mut runner = TestRunner::default();
+        for _ in 0..32 {
+            let tree = strategy.new_tree(&mut runner).unwrap();
+            assert!(size.contains(&tree.current().len()));
+        }
+    }
+
+    #[test]
+    fn ord_set_shrink_terminates() {
+        assert_shrink_terminates(&ord_set(0..1000i32, 5..10));
+    }
+
+    #[test]
+    fn vector_respects_size_range() {
+        let size = 5..10;
+        let strategy = vector(0..1000i32, size.clone());
+        let mut runner = TestRunner::default();
+        for _ in 0..32 {
+            let tree = strategy.new_tree(&mut runner).unwrap();
+            assert!(size.contains(&tree.current().len()));
+        }
+    }
+
+    #[test]
+    fn vector_shrink_terminates() {
+        assert_shrink_terminates(&vector(0..1000i32, 5..10));
+    }
+}
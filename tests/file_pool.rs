@@ -0,0 +1,33 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Exercises `FilePool`'s reload path from outside `src/util.rs`.
+//!
+//! `Handle`'s fields are private, so a real downstream crate recovering a
+//! handle for a slot restored by `FilePool::open` has to go through the
+//! public `FilePool::handle` accessor — it can't hand-build a `Handle` the
+//! way an in-module test reaching into private fields via `use super::*`
+//! could.
+
+use im::util::FilePool;
+
+#[test]
+fn reopened_pool_exposes_handles_for_recovered_slots() {
+    let path = std::env::temp_dir()
+        .join("im-util-pool-tests")
+        .join("reopen-external");
+    let _ = std::fs::remove_dir_all(&path);
+
+    {
+        let mut pool: FilePool<i32> = FilePool::new(&path);
+        pool.add(42);
+        pool.flush().expect("flush should write the node to disk");
+    }
+
+    let mut reopened: FilePool<i32> = FilePool::open(&path);
+    let handle = reopened
+        .handle(0)
+        .expect("slot 0 should have been recovered from the files left on disk");
+    assert_eq!(*reopened.read(handle), 42);
+}